@@ -0,0 +1,123 @@
+use std::{
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    sync::Mutex,
+    time::Instant,
+};
+
+use bip32::DerivationPath;
+use fastcrypto::traits::EncodeDecodeBase64;
+use rayon::prelude::*;
+use sui_keys::key_derive::generate_new_key;
+use sui_types::crypto::SignatureScheme;
+
+use crate::matcher::Matcher;
+use crate::multisig::MultisigConfig;
+
+/// Result of a successful grind: the matching address and the mnemonic and public key
+/// of the candidate signer key that produced it.
+pub struct GrindResult {
+    pub address: String,
+    pub mnemonic: String,
+    pub public_key: String,
+}
+
+/// Everything the grinder needs to generate and test one candidate key.
+pub struct GrindConfig<'a> {
+    pub matcher: &'a Matcher,
+    pub word_size: String,
+    pub threads: usize,
+    pub scheme: SignatureScheme,
+    pub derivation_path: Option<DerivationPath>,
+    /// When set, each candidate key is combined with these existing signers into a
+    /// multisig address, which is tested against `matcher` instead of the candidate's
+    /// own address.
+    pub multisig: Option<MultisigConfig>,
+}
+
+/// How often to print progress while the grind is running.
+const REPORT_INTERVAL_SECS: u64 = 1;
+
+/// Drive `config.threads` workers over the search space in parallel until one of them
+/// finds an address that satisfies `config.matcher`, then cooperatively stop every
+/// other worker.
+///
+/// Progress (total attempts, attempts/sec, elapsed time) is printed periodically, and
+/// the estimated difficulty of the match is printed once a candidate is found.
+pub fn grind(config: &GrindConfig) -> GrindResult {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(config.threads)
+        .build()
+        .expect("failed to build rayon thread pool");
+
+    let found = AtomicBool::new(false);
+    let attempts = AtomicU64::new(0);
+    let result: Mutex<Option<GrindResult>> = Mutex::new(None);
+    let start = Instant::now();
+
+    std::thread::scope(|scope| {
+        // Runs on its own OS thread, outside `pool`, so it can't be starved by the
+        // `config.threads` workers below (which each run an infinite `while !found`
+        // loop and would otherwise never yield a rayon thread for it to run on).
+        scope.spawn(|| report_progress(&found, &attempts, start));
+
+        pool.install(|| {
+            (0..config.threads).into_par_iter().for_each(|_| {
+                while !found.load(Ordering::Relaxed) {
+                    let (sui_address, keypair, _, mnemonic) = generate_new_key(
+                        config.scheme,
+                        config.derivation_path.clone(),
+                        Some(format!("word{}", config.word_size)),
+                    )
+                    .unwrap();
+
+                    attempts.fetch_add(1, Ordering::Relaxed);
+
+                    let address = match &config.multisig {
+                        Some(multisig) => {
+                            match multisig.address_with_candidate(&keypair.public()) {
+                                Ok(address) => address,
+                                Err(_) => continue,
+                            }
+                        }
+                        None => sui_address,
+                    };
+
+                    if config.matcher.is_match(&address.to_string()) {
+                        if !found.swap(true, Ordering::Relaxed) {
+                            *result.lock().unwrap() = Some(GrindResult {
+                                address: address.to_string(),
+                                mnemonic,
+                                public_key: keypair.public().encode_base64(),
+                            });
+                        }
+                        break;
+                    }
+                }
+            });
+        });
+    });
+
+    let total_attempts = attempts.load(Ordering::Relaxed);
+    let elapsed = start.elapsed().as_secs_f64();
+    let difficulty = 16f64.powi(config.matcher.fixed_hex_width() as i32);
+    println!(
+        "Found after {total_attempts} attempts in {elapsed:.2}s (~1 in {difficulty:.0} difficulty)"
+    );
+
+    result
+        .into_inner()
+        .unwrap()
+        .expect("a worker must have found a match")
+}
+
+/// Background task that prints attempts/sec until `found` flips to true.
+fn report_progress(found: &AtomicBool, attempts: &AtomicU64, start: Instant) {
+    while !found.load(Ordering::Relaxed) {
+        std::thread::sleep(std::time::Duration::from_secs(REPORT_INTERVAL_SECS));
+
+        let total = attempts.load(Ordering::Relaxed);
+        let elapsed = start.elapsed().as_secs_f64();
+        let rate = total as f64 / elapsed.max(0.001);
+        println!("{total} attempts, {rate:.0} attempts/sec, {elapsed:.0}s elapsed");
+    }
+}
@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use bip32::DerivationPath;
+use sui_keys::keystore::{AccountKeystore, FileBasedKeystore};
+use sui_types::crypto::SignatureScheme;
+
+/// Import a freshly-ground mnemonic into the keystore at `path`, the same way the Sui
+/// CLI persists keys. Returns `alias` when one is given, otherwise the imported address.
+pub fn import_keypair(
+    path: &Path,
+    mnemonic: &str,
+    scheme: SignatureScheme,
+    derivation_path: Option<DerivationPath>,
+    alias: Option<String>,
+) -> anyhow::Result<String> {
+    let mut keystore = FileBasedKeystore::new(&path.to_path_buf())?;
+
+    let address =
+        keystore.import_from_mnemonic(mnemonic, scheme, derivation_path, alias.clone())?;
+
+    Ok(alias.unwrap_or_else(|| address.to_string()))
+}
@@ -1,44 +1,247 @@
-use std::{env, process::exit, thread};
+mod grinder;
+mod keystore;
+mod matcher;
+mod multisig;
+
+use std::{path::PathBuf, process::exit, str::FromStr};
+
+use bip32::DerivationPath;
+use clap::Parser;
+use fastcrypto::traits::EncodeDecodeBase64;
+use grinder::GrindConfig;
+use matcher::Matcher;
+use multisig::MultisigConfig;
 use sui_keys::key_derive::generate_new_key;
 use sui_types::crypto::SignatureScheme;
 
+/// Grind Sui keypairs until the resulting address satisfies the requested pattern(s).
+#[derive(Parser, Debug)]
+#[command(author, version, about)]
+struct Args {
+    /// Require the address to start with this hex string (after the `0x` prefix).
+    #[arg(long)]
+    prefix: Option<String>,
+
+    /// Require the address to end with this hex string.
+    #[arg(long = "ends-with")]
+    ends_with: Option<String>,
+
+    /// Require the address to match this regex. May be passed multiple times;
+    /// all supplied constraints must pass for a candidate to count as a match.
+    #[arg(long = "regex")]
+    regex: Vec<String>,
+
+    /// Lowercase both the address and the patterns before comparing.
+    #[arg(long = "ignore-case")]
+    ignore_case: bool,
+
+    /// Number of words in the generated mnemonic.
+    #[arg(long = "word-size", default_value = "24")]
+    word_size: String,
+
+    /// Number of worker threads to grind with. Defaults to the number of available cores.
+    #[arg(long, default_value_t = num_cpus::get())]
+    threads: usize,
+
+    /// Signature scheme of the generated keypair.
+    #[arg(long, default_value = "ed25519")]
+    scheme: String,
+
+    /// BIP32/SLIP10 derivation path to use for every candidate key, e.g. `m/44'/784'/0'/0'/0'`.
+    #[arg(long = "derivation-path")]
+    derivation_path: Option<String>,
+
+    /// Import the matching keypair into this Sui keystore file instead of printing the mnemonic.
+    #[arg(long)]
+    keystore: Option<PathBuf>,
+
+    /// Alias to store the keypair under in the keystore. Requires --keystore.
+    #[arg(long)]
+    alias: Option<String>,
+
+    /// Print the mnemonic to the terminal. Off by default, since it's a secret phrase that
+    /// can be used to recreate the keypair.
+    #[arg(long = "show-mnemonic")]
+    show_mnemonic: bool,
+
+    /// Grind a multisig address instead of a plain one. An existing signer's public key and
+    /// weight, as `<base64-pubkey>:<weight>`. May be passed multiple times.
+    #[arg(long = "multisig-pk")]
+    multisig_pk: Vec<String>,
+
+    /// Weight to give the newly-ground candidate signer in the multisig.
+    #[arg(long = "multisig-new-weight", default_value_t = 1)]
+    multisig_new_weight: u8,
+
+    /// Threshold the multisig should require. Required when any --multisig-pk is given.
+    #[arg(long = "multisig-threshold")]
+    multisig_threshold: Option<u16>,
+}
+
+/// Parses `--scheme` by name rather than relying on `SignatureScheme`'s own `FromStr`
+/// (which, in the upstream crate, parses the numeric key-scheme flag, not these names).
+fn parse_scheme(s: &str) -> Option<SignatureScheme> {
+    match s {
+        "ed25519" => Some(SignatureScheme::ED25519),
+        "secp256k1" => Some(SignatureScheme::Secp256k1),
+        "secp256r1" => Some(SignatureScheme::Secp256r1),
+        _ => None,
+    }
+}
+
 fn main() {
-    let args = env::args().skip(1).collect::<Vec<String>>();
-    let mut args = args.into_iter();
+    let args = Args::parse();
+
+    if args.prefix.is_none() && args.ends_with.is_none() && args.regex.is_empty() {
+        eprintln!("error: should define at least one of --prefix, --ends-with, or --regex!");
+        exit(1);
+    }
+
+    if args.keystore.is_none() && !args.show_mnemonic {
+        eprintln!(
+            "error: the found key would be unrecoverable: pass --keystore to save it or --show-mnemonic to print it"
+        );
+        exit(1);
+    }
+
+    if args.alias.is_some() && args.keystore.is_none() {
+        eprintln!("error: --alias requires --keystore");
+        exit(1);
+    }
+
+    if args.threads == 0 {
+        eprintln!("error: --threads must be at least 1");
+        exit(1);
+    }
+
+    let prefix = args.prefix.map(|mut p| {
+        p.insert_str(0, "0x");
+        p
+    });
 
-    let mut handle_vec = vec![];
+    let matcher = Matcher::new(prefix, args.ends_with, &args.regex, args.ignore_case)
+        .unwrap_or_else(|e| {
+            eprintln!("error: {e}");
+            exit(1);
+        });
+
+    let scheme = parse_scheme(&args.scheme).unwrap_or_else(|| {
+        eprintln!(
+            "error: unknown --scheme `{}`, expected one of ed25519, secp256k1, secp256r1",
+            args.scheme
+        );
+        exit(1);
+    });
 
-    let mut prefix = args
-        .next()
-        .unwrap_or_else(|| {
-            panic!("should define a prefix!");
+    let derivation_path = args.derivation_path.map(|path| {
+        DerivationPath::from_str(&path).unwrap_or_else(|e| {
+            eprintln!("error: invalid --derivation-path `{path}`: {e}");
+            exit(1);
         })
-        .to_owned();
-    prefix.insert_str(0, "0x");
-
-    let word_size = args.next().unwrap_or("24".to_string());
-
-    for _i in 0..20 {
-        let prefix = prefix.clone();
-        let word_size = word_size.clone();
-
-        let handle = thread::spawn(move || loop {
-            let (sui_address, _, _, mnemonic) = generate_new_key(
-                SignatureScheme::ED25519,
-                None,
-                Some(format!("word{}", word_size)),
-            )
-            .unwrap();
-
-            if sui_address.to_string().starts_with(&prefix) {
-                println!("Your sui address: {}", sui_address);
-                println!("Your mnemonic: {}", mnemonic);
-                exit(1);
-            };
+    });
+
+    let multisig = if args.multisig_pk.is_empty() {
+        None
+    } else {
+        let existing_signers = args
+            .multisig_pk
+            .iter()
+            .map(|spec| {
+                multisig::parse_signer(spec).unwrap_or_else(|e| {
+                    eprintln!("error: invalid --multisig-pk `{spec}`: {e}");
+                    exit(1);
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let threshold = args.multisig_threshold.unwrap_or_else(|| {
+            eprintln!("error: --multisig-threshold is required when --multisig-pk is given");
+            exit(1);
         });
-        handle_vec.push(handle);
+
+        let total_weight: u32 = existing_signers
+            .iter()
+            .map(|signer| signer.weight as u32)
+            .sum::<u32>()
+            + args.multisig_new_weight as u32;
+        if threshold as u32 > total_weight {
+            eprintln!(
+                "error: --multisig-threshold ({threshold}) can never be met by the supplied signers (total weight {total_weight})"
+            );
+            exit(1);
+        }
+
+        let config = MultisigConfig {
+            existing_signers,
+            candidate_weight: args.multisig_new_weight,
+            threshold,
+        };
+
+        // Construct the multisig with a throwaway candidate key up front, so a
+        // structurally impossible config (bad weights/threshold, too many signers, a
+        // duplicate pubkey) is rejected now instead of making every real candidate
+        // error out and leaving the grinder spinning forever with no output.
+        let (_, throwaway, _, _) = generate_new_key(
+            scheme,
+            derivation_path.clone(),
+            Some(format!("word{}", args.word_size)),
+        )
+        .expect("failed to generate throwaway key for multisig validation");
+        if let Err(e) = config.address_with_candidate(&throwaway.public()) {
+            eprintln!("error: invalid multisig configuration: {e}");
+            exit(1);
+        }
+
+        Some(config)
+    };
+
+    let config = GrindConfig {
+        matcher: &matcher,
+        word_size: args.word_size,
+        threads: args.threads,
+        scheme,
+        derivation_path: derivation_path.clone(),
+        multisig,
+    };
+
+    let result = grinder::grind(&config);
+
+    println!("Your sui address: {}", result.address);
+
+    if let Some(multisig) = &config.multisig {
+        println!("Multisig configuration (threshold {}):", multisig.threshold);
+        for signer in &multisig.existing_signers {
+            println!(
+                "  {} (weight {})",
+                signer.public_key.encode_base64(),
+                signer.weight
+            );
+        }
+        println!(
+            "  {} (weight {}) <- new signer",
+            result.public_key, multisig.candidate_weight
+        );
+    }
+
+    if let Some(keystore_path) = args.keystore {
+        let alias = keystore::import_keypair(
+            &keystore_path,
+            &result.mnemonic,
+            scheme,
+            derivation_path,
+            args.alias,
+        )
+        .unwrap_or_else(|e| {
+            eprintln!("error: failed to import keypair into keystore: {e}");
+            exit(1);
+        });
+        println!(
+            "Imported into keystore `{}` as `{alias}`",
+            keystore_path.display()
+        );
+    }
+
+    if args.show_mnemonic {
+        println!("Your mnemonic: {}", result.mnemonic);
     }
-    handle_vec
-        .into_iter()
-        .for_each(|handle| handle.join().unwrap());
 }
@@ -0,0 +1,206 @@
+use std::borrow::Cow;
+
+use regex::{RegexSet, RegexSetBuilder};
+use thiserror::Error;
+
+/// A Sui address is 32 bytes, i.e. 64 hex characters after the `0x` prefix.
+const ADDRESS_HEX_WIDTH: usize = 64;
+
+#[derive(Debug, Error)]
+pub enum MatcherError {
+    #[error("`{0}` contains a character that can never appear in a hex Sui address")]
+    NotHex(String),
+    #[error("`{pattern}` is {len} hex characters long, but a Sui address is only {ADDRESS_HEX_WIDTH} characters wide")]
+    TooLong { pattern: String, len: usize },
+    #[error("`{0}` contains an uppercase hex character, which a Sui address never displays as; pass --ignore-case or lowercase it")]
+    UppercaseWithoutIgnoreCase(String),
+    #[error(transparent)]
+    Regex(#[from] regex::Error),
+}
+
+/// Compiled set of constraints an address must satisfy to count as a vanity match.
+///
+/// Patterns are compiled once up front so the hot loop in the grinder only ever
+/// does cheap string comparisons and a single `RegexSet` scan per candidate.
+pub struct Matcher {
+    starts_with: Option<String>,
+    ends_with: Option<String>,
+    regex_set: Option<RegexSet>,
+    ignore_case: bool,
+}
+
+/// Rejects a prefix/suffix pattern up front if it could never match any real
+/// Sui address, instead of letting the grinder spin forever on an impossible target.
+///
+/// `SuiAddress` always displays as lowercase hex, so without `ignore_case` an
+/// uppercase pattern is just as impossible to match as a non-hex one.
+fn validate_hex_pattern(pattern: &str, ignore_case: bool) -> Result<(), MatcherError> {
+    let hex_part = pattern.strip_prefix("0x").unwrap_or(pattern);
+
+    if !hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(MatcherError::NotHex(pattern.to_owned()));
+    }
+
+    if !ignore_case && hex_part.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err(MatcherError::UppercaseWithoutIgnoreCase(pattern.to_owned()));
+    }
+
+    if hex_part.len() > ADDRESS_HEX_WIDTH {
+        return Err(MatcherError::TooLong {
+            pattern: pattern.to_owned(),
+            len: hex_part.len(),
+        });
+    }
+
+    Ok(())
+}
+
+impl Matcher {
+    pub fn new(
+        starts_with: Option<String>,
+        ends_with: Option<String>,
+        patterns: &[String],
+        ignore_case: bool,
+    ) -> Result<Self, MatcherError> {
+        let fold = |s: String| if ignore_case { s.to_lowercase() } else { s };
+
+        if let Some(prefix) = &starts_with {
+            validate_hex_pattern(prefix, ignore_case)?;
+        }
+        if let Some(suffix) = &ends_with {
+            validate_hex_pattern(suffix, ignore_case)?;
+            if suffix.starts_with("0x") {
+                return Err(MatcherError::NotHex(suffix.to_owned()));
+            }
+        }
+
+        let regex_set = if patterns.is_empty() {
+            None
+        } else {
+            Some(
+                RegexSetBuilder::new(patterns)
+                    .case_insensitive(ignore_case)
+                    .build()?,
+            )
+        };
+
+        Ok(Self {
+            starts_with: starts_with.map(fold),
+            ends_with: ends_with.map(fold),
+            regex_set,
+            ignore_case,
+        })
+    }
+
+    /// Returns true only if every supplied constraint (prefix, suffix, regex set) passes.
+    pub fn is_match(&self, address: &str) -> bool {
+        let address: Cow<str> = if self.ignore_case {
+            Cow::Owned(address.to_lowercase())
+        } else {
+            Cow::Borrowed(address)
+        };
+        let address = address.as_ref();
+
+        if let Some(prefix) = &self.starts_with {
+            if !address.starts_with(prefix) {
+                return false;
+            }
+        }
+
+        if let Some(suffix) = &self.ends_with {
+            if !address.ends_with(suffix) {
+                return false;
+            }
+        }
+
+        if let Some(regex_set) = &self.regex_set {
+            if regex_set.matches(address).iter().count() != regex_set.len() {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Number of hex characters pinned down by the prefix/suffix constraints, used to
+    /// estimate how rare a match is. Does not account for `--regex`, whose pattern
+    /// space isn't generally expressible as a fixed character count.
+    pub fn fixed_hex_width(&self) -> usize {
+        let prefix_width = self
+            .starts_with
+            .as_deref()
+            .map(|p| p.strip_prefix("0x").unwrap_or(p).len())
+            .unwrap_or(0);
+        let suffix_width = self.ends_with.as_deref().map(str::len).unwrap_or(0);
+
+        prefix_width + suffix_width
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_prefix_and_suffix() {
+        let matcher = Matcher::new(
+            Some("0xdead".to_string()),
+            Some("beef".to_string()),
+            &[],
+            false,
+        )
+        .unwrap();
+
+        assert!(matcher.is_match("0xdead00000000000000000000000000000000000000000000000000000beef"));
+        assert!(
+            !matcher.is_match("0xbeef00000000000000000000000000000000000000000000000000000dead")
+        );
+    }
+
+    #[test]
+    fn ignore_case_folds_prefix_and_address() {
+        let matcher = Matcher::new(Some("0xDEAD".to_string()), None, &[], true).unwrap();
+
+        assert!(matcher.is_match("0xdead000000000000000000000000000000000000000000000000000000000"));
+    }
+
+    #[test]
+    fn regex_respects_ignore_case() {
+        let matcher = Matcher::new(None, None, &["^0xDEAD".to_string()], true).unwrap();
+
+        assert!(matcher.is_match("0xdead000000000000000000000000000000000000000000000000000000000"));
+    }
+
+    #[test]
+    fn regex_is_case_sensitive_by_default() {
+        let matcher = Matcher::new(None, None, &["^0xDEAD".to_string()], false).unwrap();
+
+        assert!(
+            !matcher.is_match("0xdead000000000000000000000000000000000000000000000000000000000")
+        );
+    }
+
+    #[test]
+    fn rejects_non_hex_prefix() {
+        let err = Matcher::new(Some("zzzz".to_string()), None, &[], false).unwrap_err();
+        assert!(matches!(err, MatcherError::NotHex(_)));
+    }
+
+    #[test]
+    fn rejects_prefix_longer_than_an_address() {
+        let pattern = "a".repeat(ADDRESS_HEX_WIDTH + 1);
+        let err = Matcher::new(Some(pattern), None, &[], false).unwrap_err();
+        assert!(matches!(err, MatcherError::TooLong { .. }));
+    }
+
+    #[test]
+    fn rejects_uppercase_prefix_without_ignore_case() {
+        let err = Matcher::new(Some("DEAD".to_string()), None, &[], false).unwrap_err();
+        assert!(matches!(err, MatcherError::UppercaseWithoutIgnoreCase(_)));
+    }
+
+    #[test]
+    fn allows_uppercase_prefix_with_ignore_case() {
+        assert!(Matcher::new(Some("DEAD".to_string()), None, &[], true).is_ok());
+    }
+}
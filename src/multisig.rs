@@ -0,0 +1,91 @@
+use fastcrypto::traits::EncodeDecodeBase64;
+use sui_types::base_types::SuiAddress;
+use sui_types::crypto::PublicKey;
+use sui_types::multisig::MultiSigPublicKey;
+
+/// An existing signer supplied on the command line as part of a multisig account.
+#[derive(Clone)]
+pub struct SignerSpec {
+    pub public_key: PublicKey,
+    pub weight: u8,
+}
+
+/// Parses a `<base64-pubkey>:<weight>` pair, the format the Sui CLI's own multisig
+/// commands use for `--pks`/`--weights`.
+pub fn parse_signer(spec: &str) -> anyhow::Result<SignerSpec> {
+    let (pk, weight) = spec
+        .rsplit_once(':')
+        .ok_or_else(|| anyhow::anyhow!("expected `<base64-pubkey>:<weight>`, got `{spec}`"))?;
+
+    Ok(SignerSpec {
+        public_key: PublicKey::decode_base64(pk)
+            .map_err(|e| anyhow::anyhow!("invalid public key `{pk}`: {e}"))?,
+        weight: weight
+            .parse()
+            .map_err(|e| anyhow::anyhow!("invalid weight `{weight}`: {e}"))?,
+    })
+}
+
+/// Configuration for grinding a vanity address for a multisig account: the existing
+/// signers, the weight to give the freshly-ground candidate key, and the threshold.
+pub struct MultisigConfig {
+    pub existing_signers: Vec<SignerSpec>,
+    pub candidate_weight: u8,
+    pub threshold: u16,
+}
+
+impl MultisigConfig {
+    /// Computes the multisig address that results from adding `candidate` to the
+    /// existing signers at `self.candidate_weight`.
+    pub fn address_with_candidate(&self, candidate: &PublicKey) -> anyhow::Result<SuiAddress> {
+        let mut public_keys: Vec<PublicKey> = self
+            .existing_signers
+            .iter()
+            .map(|signer| signer.public_key.clone())
+            .collect();
+        let mut weights: Vec<u8> = self
+            .existing_signers
+            .iter()
+            .map(|signer| signer.weight)
+            .collect();
+
+        public_keys.push(candidate.clone());
+        weights.push(self.candidate_weight);
+
+        let multisig_pk = MultiSigPublicKey::new(public_keys, weights, self.threshold)?;
+        Ok(SuiAddress::from(&multisig_pk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Base64 of a 1-byte ED25519 flag followed by 32 zero bytes: not a real key, but a
+    // validly-shaped one, which is all `parse_signer` itself checks.
+    const PLACEHOLDER_PK: &str = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+
+    #[test]
+    fn parses_pubkey_and_weight() {
+        let spec = parse_signer(&format!("{PLACEHOLDER_PK}:2")).unwrap();
+        assert_eq!(spec.weight, 2);
+    }
+
+    #[test]
+    fn rejects_missing_separator() {
+        let err = parse_signer(PLACEHOLDER_PK).unwrap_err();
+        assert!(err.to_string().contains("expected"));
+    }
+
+    #[test]
+    fn rejects_non_numeric_weight() {
+        let err = parse_signer(&format!("{PLACEHOLDER_PK}:many")).unwrap_err();
+        assert!(err.to_string().contains("invalid weight"));
+    }
+
+    #[test]
+    fn rejects_invalid_base64_pubkey() {
+        let err = parse_signer("not-valid-base64:1").unwrap_err();
+        assert!(err.to_string().contains("invalid public key"));
+    }
+}